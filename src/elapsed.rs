@@ -0,0 +1,140 @@
+//! Stateful, elapsed-time-aware wrapper around [`Exponential`].
+
+use std::time::{Duration, Instant};
+
+use crate::Exponential;
+
+/// Abstracts the time source behind [`ExponentialElapsed`] so elapsed-time logic can be
+/// driven deterministically in tests instead of calling [`Instant::now`] directly.
+pub trait Clock {
+    /// Returns the current instant according to this clock.
+    fn now(&self) -> Instant;
+}
+
+/// The default [`Clock`], backed by [`Instant::now`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A [`Clock`] whose time can be advanced manually, for deterministic unit tests.
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    base: Instant,
+    offset: Duration,
+}
+
+impl TestClock {
+    /// Creates a clock starting at the instant it was constructed.
+    pub fn new() -> Self {
+        Self {
+            base: Instant::now(),
+            offset: Duration::ZERO,
+        }
+    }
+
+    /// Moves this clock's `now()` forward by `by`.
+    pub fn advance(&mut self, by: Duration) {
+        self.offset += by;
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.base + self.offset
+    }
+}
+
+/// Wraps an [`Exponential`] schedule with a deadline: [`next_backoff`](Self::next_backoff)
+/// returns `None` once `max_elapsed_time` has passed since the last
+/// [`reset`](Self::reset) (or since construction).
+pub struct ExponentialElapsed<C = SystemClock> {
+    backoff: Exponential,
+    max_elapsed_time: Option<Duration>,
+    clock: C,
+    start: Instant,
+    attempt: usize,
+}
+
+impl ExponentialElapsed<SystemClock> {
+    /// Wraps `backoff`, stopping once `max_elapsed_time` has elapsed since now.
+    pub fn new(backoff: Exponential, max_elapsed_time: Option<Duration>) -> Self {
+        Self::with_clock(backoff, max_elapsed_time, SystemClock)
+    }
+}
+
+impl<C: Clock> ExponentialElapsed<C> {
+    /// Like [`new`](Self::new), but driven by a custom [`Clock`] (e.g. [`TestClock`]).
+    pub fn with_clock(backoff: Exponential, max_elapsed_time: Option<Duration>, clock: C) -> Self {
+        let start = clock.now();
+        Self {
+            backoff,
+            max_elapsed_time,
+            clock,
+            start,
+            attempt: 0,
+        }
+    }
+
+    /// Returns the next backoff delay, or `None` once `max_elapsed_time` has elapsed.
+    pub fn next_backoff(&mut self) -> Option<Duration> {
+        if let Some(max_elapsed_time) = self.max_elapsed_time {
+            if self.clock.now().duration_since(self.start) >= max_elapsed_time {
+                return None;
+            }
+        }
+        let delay = self.backoff.duration(self.attempt);
+        self.attempt += 1;
+        Some(delay)
+    }
+
+    /// Re-arms the start time and attempt counter so this policy can be reused across
+    /// independent operations.
+    pub fn reset(&mut self) {
+        self.start = self.clock.now();
+        self.attempt = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExponentialBackoffBuilder;
+
+    #[test]
+    fn stops_after_max_elapsed_time() {
+        let backoff = ExponentialBackoffBuilder::default().build();
+        let clock = TestClock::new();
+        let mut elapsed =
+            ExponentialElapsed::with_clock(backoff, Some(Duration::from_secs(1)), clock);
+
+        assert!(elapsed.next_backoff().is_some());
+
+        elapsed.clock.advance(Duration::from_secs(2));
+        assert!(elapsed.next_backoff().is_none());
+    }
+
+    #[test]
+    fn reset_re_arms_the_deadline() {
+        let backoff = ExponentialBackoffBuilder::default().build();
+        let clock = TestClock::new();
+        let mut elapsed =
+            ExponentialElapsed::with_clock(backoff, Some(Duration::from_secs(1)), clock);
+
+        elapsed.clock.advance(Duration::from_secs(2));
+        assert!(elapsed.next_backoff().is_none());
+
+        elapsed.reset();
+        assert!(elapsed.next_backoff().is_some());
+    }
+}
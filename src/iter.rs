@@ -0,0 +1,51 @@
+//! Iterator adaptor for driving a retry loop without tracking the attempt counter.
+
+use std::time::Duration;
+
+use crate::Exponential;
+
+impl Exponential {
+    /// Returns an iterator that yields `duration(0)`, `duration(1)`, ... and stops once
+    /// `max_retries` is exhausted (or never, if unset).
+    pub fn iter(&self) -> Iter {
+        Iter {
+            backoff: self.clone(),
+            attempt: 0,
+        }
+    }
+}
+
+/// Iterator returned by [`Exponential::iter`].
+///
+/// Owns a clone of the schedule (rather than borrowing it) so that, for
+/// [`JitterMode::Decorrelated`](crate::JitterMode::Decorrelated), cloning an `Iter` yields
+/// an independent jitter sequence instead of two iterators mutating the same shared state.
+#[derive(Clone)]
+pub struct Iter {
+    backoff: Exponential,
+    attempt: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if let Some(max_retries) = self.backoff.max_retries {
+            if self.attempt >= max_retries {
+                return None;
+            }
+        }
+        let duration = self.backoff.duration(self.attempt);
+        self.attempt += 1;
+        Some(duration)
+    }
+}
+
+impl IntoIterator for &Exponential {
+    type Item = Duration;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
@@ -0,0 +1,24 @@
+//! Jitter strategies selectable on [`crate::ExponentialBackoffBuilder`].
+
+/// Strategy used to randomize the computed backoff delay.
+///
+/// `Full` and `Equal` are stateless functions of `attempt` (and the `max` cap); the AWS
+/// "decorrelated" strategy is stateful, deriving each delay from the previous one rather
+/// than from `attempt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JitterMode {
+    /// Adds uniform additive jitter in `[0, jitter]` to the computed delay. The original,
+    /// and default, behavior.
+    #[default]
+    Additive,
+    /// AWS "full jitter": `rand_range(0, min(cap, base))`.
+    Full,
+    /// AWS "equal jitter": half of the clamped base, plus uniform jitter over the other half.
+    Equal,
+    /// AWS "decorrelated jitter": `rand_range(interval, prev * 3)`, clamped to `max`, where
+    /// `prev` is the previously returned delay (the first delay starts from `interval`).
+    Decorrelated,
+    /// Proportional jitter: draws the delay from `base * [1 - randomization_factor, 1 +
+    /// randomization_factor]`, scaling jitter with the interval instead of a fixed ceiling.
+    Proportional,
+}
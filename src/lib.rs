@@ -20,15 +20,31 @@
 //! }
 //! ```
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use rand::Rng;
 use std::time::Duration;
 
+mod elapsed;
+mod iter;
+mod jitter;
+mod retry;
+
+pub use elapsed::{Clock, ExponentialElapsed, SystemClock, TestClock};
+pub use iter::Iter;
+pub use jitter::JitterMode;
+pub use retry::{Retry, Retryable};
+
 /// Configures an ExponentialBackoff instance for use.
 pub struct ExponentialBackoffBuilder {
     factor: f64,
     interval: Duration,
     jitter: Duration,
+    jitter_mode: JitterMode,
+    randomization_factor: f64,
     max: Option<Duration>,
+    max_retries: Option<usize>,
+    max_elapsed_time: Option<Duration>,
 }
 
 impl Default for ExponentialBackoffBuilder {
@@ -38,7 +54,11 @@ impl Default for ExponentialBackoffBuilder {
             factor: 1.75,
             interval: Duration::from_millis(500),
             jitter: Duration::from_millis(150),
+            jitter_mode: JitterMode::Additive,
+            randomization_factor: 0.0,
             max: None,
+            max_retries: None,
+            max_elapsed_time: None,
         }
     }
 }
@@ -64,6 +84,30 @@ impl ExponentialBackoffBuilder {
         self
     }
 
+    /// JitterMode selects the randomization strategy applied to the computed delay.
+    /// Defaults to [`JitterMode::Additive`], which uses the fixed `jitter` ceiling.
+    #[inline]
+    pub const fn jitter_mode(mut self, jitter_mode: JitterMode) -> Self {
+        self.jitter_mode = jitter_mode;
+        self
+    }
+
+    /// RandomizationFactor sets the proportional jitter factor used by
+    /// [`JitterMode::Proportional`], which draws the delay from `base * [1 - factor, 1 +
+    /// factor]`.
+    ///
+    /// # Panics
+    /// Panics if `factor` is outside `0.0..=1.0`.
+    #[inline]
+    pub fn randomization_factor(mut self, factor: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&factor),
+            "randomization_factor must be within 0.0..=1.0"
+        );
+        self.randomization_factor = factor;
+        self
+    }
+
     /// Max sets the maximum timeout despite the number of attempts. none/zero is the default.
     #[inline]
     pub const fn max(mut self, max: Duration) -> Self {
@@ -71,17 +115,39 @@ impl ExponentialBackoffBuilder {
         self
     }
 
+    /// MaxRetries caps the number of attempts yielded by [`Exponential::iter`]. None/unset
+    /// means the iterator never stops on its own.
+    #[inline]
+    pub const fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// MaxElapsedTime sets the deadline used by [`Exponential::elapsed`]. None/unset means
+    /// the resulting [`ExponentialElapsed`] never stops on its own.
+    #[inline]
+    pub const fn max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = Some(max_elapsed_time);
+        self
+    }
+
     /// finalizes the configuration and returns a usable [Exponential] instance.
     #[inline]
-    pub const fn build(self) -> Exponential {
+    pub fn build(self) -> Exponential {
+        let interval = self.interval.as_nanos() as f64;
         Exponential {
             factor: self.factor,
-            interval: self.interval.as_nanos() as f64,
+            interval,
             jitter: self.jitter.as_nanos() as f64,
+            jitter_mode: self.jitter_mode,
+            randomization_factor: self.randomization_factor,
             max: match self.max {
                 Some(d) => Some(d.as_nanos() as u64),
                 None => None,
             },
+            max_retries: self.max_retries,
+            max_elapsed_time: self.max_elapsed_time,
+            prev_sleep: AtomicU64::new(interval as u64),
         }
     }
 }
@@ -91,19 +157,128 @@ pub struct Exponential {
     factor: f64,
     interval: f64,
     jitter: f64,
+    jitter_mode: JitterMode,
+    randomization_factor: f64,
     max: Option<u64>,
+    max_retries: Option<usize>,
+    max_elapsed_time: Option<Duration>,
+    /// Previous delay (in nanoseconds) used by [`JitterMode::Decorrelated`]; carried via an
+    /// `AtomicU64` (rather than a `Cell`) so `duration` can stay a `&self` method like the
+    /// other jitter modes without making `Exponential` lose `Sync`.
+    prev_sleep: AtomicU64,
+}
+
+impl Clone for Exponential {
+    fn clone(&self) -> Self {
+        Self {
+            factor: self.factor,
+            interval: self.interval,
+            jitter: self.jitter,
+            jitter_mode: self.jitter_mode,
+            randomization_factor: self.randomization_factor,
+            max: self.max,
+            max_retries: self.max_retries,
+            max_elapsed_time: self.max_elapsed_time,
+            prev_sleep: AtomicU64::new(self.prev_sleep.load(Ordering::Relaxed)),
+        }
+    }
 }
 
 impl Exponential {
     /// returns the calculated backoff duration for backoff and retries based on the attempt.
     pub fn duration(&self, attempt: usize) -> Duration {
-        let nanoseconds = (self.factor.powi(attempt as i32) * self.interval
-            + rand::thread_rng().gen_range(0.0..=self.jitter)) as u64;
+        let nanoseconds = match self.jitter_mode {
+            JitterMode::Additive => self.additive_jitter(attempt),
+            JitterMode::Full => self.full_jitter(attempt),
+            JitterMode::Equal => self.equal_jitter(attempt),
+            JitterMode::Decorrelated => self.decorrelated_jitter(),
+            JitterMode::Proportional => self.proportional_jitter(attempt),
+        };
+        Duration::from_nanos(nanoseconds)
+    }
+
+    /// The base exponential delay for `attempt`, clamped to `max` if set. Non-finite bases
+    /// (e.g. `factor.powi(attempt)` overflowing to infinity) are treated as `f64::MAX` so
+    /// callers can keep doing arithmetic on the result instead of propagating NaN/inf.
+    fn clamped_base(&self, attempt: usize) -> f64 {
+        let base = self.factor.powi(attempt as i32) * self.interval;
+        let base = if base.is_finite() { base } else { f64::MAX };
         match self.max {
-            Some(max) if nanoseconds > max => Duration::from_nanos(max),
-            _ => Duration::from_nanos(nanoseconds),
+            Some(max) if base > max as f64 => max as f64,
+            _ => base,
         }
     }
+
+    /// Original behavior: `base + rand_range(0, jitter)`.
+    fn additive_jitter(&self, attempt: usize) -> u64 {
+        let base = self.factor.powi(attempt as i32) * self.interval;
+        let nanoseconds = base + rand::thread_rng().gen_range(0.0..=self.jitter);
+        saturating_nanos(nanoseconds, self.max)
+    }
+
+    /// AWS full jitter: `rand_range(0, min(cap, base))`.
+    fn full_jitter(&self, attempt: usize) -> u64 {
+        let cap = self.clamped_base(attempt);
+        let nanoseconds = rand::thread_rng().gen_range(0.0..=cap);
+        saturating_nanos(nanoseconds, self.max)
+    }
+
+    /// AWS equal jitter: `temp/2 + rand_range(0, temp/2)` where `temp = min(cap, base)`.
+    fn equal_jitter(&self, attempt: usize) -> u64 {
+        let temp = self.clamped_base(attempt);
+        let half = temp / 2.0;
+        let nanoseconds = half + rand::thread_rng().gen_range(0.0..=half);
+        saturating_nanos(nanoseconds, self.max)
+    }
+
+    /// AWS decorrelated jitter: `min(cap, rand_range(interval, prev * 3))`, carrying `prev`.
+    fn decorrelated_jitter(&self) -> u64 {
+        let prev = self.prev_sleep.load(Ordering::Relaxed) as f64;
+        let upper = (prev * 3.0).max(self.interval);
+        let sleep = rand::thread_rng().gen_range(self.interval..=upper);
+        let nanoseconds = saturating_nanos(sleep, self.max);
+        self.prev_sleep.store(nanoseconds, Ordering::Relaxed);
+        nanoseconds
+    }
+
+    /// Proportional jitter: `rand_range(base * (1 - randomization_factor), base * (1 +
+    /// randomization_factor))`.
+    fn proportional_jitter(&self, attempt: usize) -> u64 {
+        let base = self.factor.powi(attempt as i32) * self.interval;
+        let low = base * (1.0 - self.randomization_factor);
+        let high = base * (1.0 + self.randomization_factor);
+        let nanoseconds = if low < high {
+            rand::thread_rng().gen_range(low..=high)
+        } else {
+            base
+        };
+        saturating_nanos(nanoseconds, self.max)
+    }
+
+    /// Wraps this schedule in an [`ExponentialElapsed`] that stops once the configured
+    /// `max_elapsed_time` has passed.
+    pub fn elapsed(&self) -> ExponentialElapsed {
+        ExponentialElapsed::new(self.clone(), self.max_elapsed_time)
+    }
+
+    /// Like [`elapsed`](Self::elapsed), but driven by a custom [`Clock`] (e.g. [`TestClock`]).
+    pub fn elapsed_with_clock<C: Clock>(&self, clock: C) -> ExponentialElapsed<C> {
+        ExponentialElapsed::with_clock(self.clone(), self.max_elapsed_time, clock)
+    }
+}
+
+/// Converts possibly-overflowing nanosecond float math into nanoseconds as a `u64`,
+/// saturating to `max` (or `u64::MAX` if `max` is unset) instead of wrapping or collapsing
+/// to a tiny/zero value when `nanoseconds` is non-finite or exceeds `u64::MAX`.
+fn saturating_nanos(nanoseconds: f64, max: Option<u64>) -> u64 {
+    if !nanoseconds.is_finite() || nanoseconds > u64::MAX as f64 {
+        return max.unwrap_or(u64::MAX);
+    }
+    let nanoseconds = nanoseconds as u64;
+    match max {
+        Some(max) if nanoseconds > max => max,
+        _ => nanoseconds,
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +286,15 @@ mod tests {
 
     use super::*;
 
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn exponential_is_sync() {
+        // `retry()`/`iter()`/`elapsed()` all exist to be shared (e.g. via `Arc`) across
+        // tokio tasks, so `Exponential` must stay `Sync` regardless of `JitterMode`.
+        assert_sync::<Exponential>();
+    }
+
     #[test]
     fn no_jitter() {
         let bo = ExponentialBackoffBuilder::default()
@@ -140,4 +324,88 @@ mod tests {
         assert!(bo.duration(4) <= Duration::from_nanos(4689453125));
         assert!(bo.duration(5) <= Duration::from_secs(5));
     }
+
+    #[test]
+    fn full_jitter_respects_cap() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter_mode(JitterMode::Full)
+            .max(Duration::from_secs(5))
+            .build();
+
+        for attempt in 0..=10 {
+            assert!(bo.duration(attempt) <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn equal_jitter_respects_cap() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter_mode(JitterMode::Equal)
+            .max(Duration::from_secs(5))
+            .build();
+
+        for attempt in 0..=10 {
+            assert!(bo.duration(attempt) <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn decorrelated_jitter_respects_cap() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter_mode(JitterMode::Decorrelated)
+            .max(Duration::from_secs(5))
+            .build();
+
+        for _ in 0..=10 {
+            assert!(bo.duration(0) <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn large_attempt_clamps_to_max_instead_of_overflowing() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter(Duration::default())
+            .max(Duration::from_secs(5))
+            .build();
+
+        assert_eq!(bo.duration(1000), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn large_factor_clamps_to_max_instead_of_overflowing() {
+        let bo = ExponentialBackoffBuilder::default()
+            .factor(1000.0)
+            .jitter(Duration::default())
+            .max(Duration::from_secs(5))
+            .build();
+
+        assert_eq!(bo.duration(50), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn no_max_set_saturates_instead_of_overflowing() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter(Duration::default())
+            .build();
+
+        assert_eq!(bo.duration(1000), Duration::from_nanos(u64::MAX));
+    }
+
+    #[test]
+    fn proportional_jitter_stays_within_randomization_factor() {
+        let bo = ExponentialBackoffBuilder::default()
+            .jitter_mode(JitterMode::Proportional)
+            .randomization_factor(0.5)
+            .build();
+
+        let base = Duration::from_millis(875); // factor(1.75) * interval(500ms) at attempt 1
+        assert!(bo.duration(1) >= base / 2);
+        assert!(bo.duration(1) <= base + base / 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "randomization_factor must be within 0.0..=1.0")]
+    fn randomization_factor_rejects_out_of_range() {
+        ExponentialBackoffBuilder::default().randomization_factor(1.5);
+    }
 }
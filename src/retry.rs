@@ -0,0 +1,215 @@
+//! Drives a fallible async operation against an [`Exponential`] backoff schedule.
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use pin_project::pin_project;
+use tokio::time::Sleep;
+
+use crate::Exponential;
+
+/// Extension trait for retrying a fallible async operation against an [`Exponential`] schedule.
+pub trait Retryable {
+    /// Wraps `op` in a [`Retry`] future that re-invokes it until it succeeds or the
+    /// [`Retry::when`] predicate refuses to retry, sleeping `self.duration(attempt)`
+    /// between failed attempts.
+    fn retry<Op, Fut, T, E>(&self, op: Op) -> Retry<Op, Fut, T, E>
+    where
+        Op: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>;
+}
+
+impl Retryable for Exponential {
+    fn retry<Op, Fut, T, E>(&self, mut op: Op) -> Retry<Op, Fut, T, E>
+    where
+        Op: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let first = op();
+        Retry {
+            backoff: self.clone(),
+            op,
+            when: None,
+            notify: None,
+            attempt: 0,
+            state: State::Calling(first),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[pin_project(project = StateProj)]
+enum State<Fut> {
+    Calling(#[pin] Fut),
+    Sleeping(#[pin] Sleep),
+}
+
+/// Predicate deciding whether an error should trigger another attempt.
+type WhenPredicate<E> = Box<dyn FnMut(&E) -> bool + Send>;
+/// Hook invoked with the error and upcoming delay before each sleep.
+type NotifyHook<E> = Box<dyn FnMut(&E, Duration) + Send>;
+
+/// A future, returned by [`Retryable::retry`], that retries a fallible operation on a
+/// schedule.
+#[pin_project]
+pub struct Retry<Op, Fut, T, E> {
+    backoff: Exponential,
+    op: Op,
+    when: Option<WhenPredicate<E>>,
+    notify: Option<NotifyHook<E>>,
+    attempt: usize,
+    #[pin]
+    state: State<Fut>,
+    // `T` only appears in the `Fut: Future<Output = Result<T, E>>` bound on the impls below,
+    // so it needs a field to tie it to the struct.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<Op, Fut, T, E> Retry<Op, Fut, T, E> {
+    /// Only retries when `predicate` returns `true` for the error; otherwise the error
+    /// is returned immediately instead of sleeping.
+    pub fn when<F>(mut self, predicate: F) -> Self
+    where
+        F: FnMut(&E) -> bool + Send + 'static,
+    {
+        self.when = Some(Box::new(predicate));
+        self
+    }
+
+    /// Called with the error and the upcoming delay before each sleep.
+    pub fn notify<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut(&E, Duration) + Send + 'static,
+    {
+        self.notify = Some(Box::new(hook));
+        self
+    }
+}
+
+impl<Op, Fut, T, E> Future for Retry<Op, Fut, T, E>
+where
+    Op: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Calling(fut) => match fut.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(value)) => return Poll::Ready(Ok(value)),
+                    Poll::Ready(Err(err)) => {
+                        if !this.when.as_mut().is_none_or(|when| when(&err)) {
+                            return Poll::Ready(Err(err));
+                        }
+                        let delay = this.backoff.duration(*this.attempt);
+                        *this.attempt += 1;
+                        if let Some(notify) = this.notify.as_mut() {
+                            notify(&err, delay);
+                        }
+                        this.state.set(State::Sleeping(tokio::time::sleep(delay)));
+                    }
+                },
+                StateProj::Sleeping(sleep) => match sleep.poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => {
+                        let fut = (this.op)();
+                        this.state.set(State::Calling(fut));
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::ExponentialBackoffBuilder;
+
+    fn fast_backoff() -> Exponential {
+        ExponentialBackoffBuilder::default()
+            .interval(Duration::from_millis(1))
+            .jitter(Duration::default())
+            .build()
+    }
+
+    #[tokio::test]
+    async fn succeeds_after_failures() {
+        let backoff = fast_backoff();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let result: Result<&str, &str> = backoff
+            .retry(|| {
+                let calls = calls.clone();
+                async move {
+                    if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Err("not yet")
+                    } else {
+                        Ok("done")
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result, Ok("done"));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn when_rejecting_short_circuits_without_sleeping() {
+        let backoff = fast_backoff();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let result: Result<(), &str> = backoff
+            .retry(|| {
+                let calls = calls.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err("fatal")
+                }
+            })
+            .when(|_: &&str| false)
+            .await;
+
+        assert_eq!(result, Err("fatal"));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn notify_fires_once_per_failed_attempt() {
+        let backoff = fast_backoff();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifications = Arc::new(Mutex::new(Vec::new()));
+        let notifications_clone = notifications.clone();
+
+        let result: Result<&str, &str> = backoff
+            .retry(|| {
+                let calls = calls.clone();
+                async move {
+                    if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Err("retry me")
+                    } else {
+                        Ok("done")
+                    }
+                }
+            })
+            .notify(move |err, delay| {
+                notifications_clone.lock().unwrap().push((*err, delay));
+            })
+            .await;
+
+        assert_eq!(result, Ok("done"));
+        let notes = notifications.lock().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert!(notes.iter().all(|(err, _)| *err == "retry me"));
+    }
+}